@@ -0,0 +1,102 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use interner::StringKey;
+use std::fmt;
+
+/// An error produced while deserializing a `Schema` out of a FlatBuffer.
+///
+/// Modeled after async-graphql's parser errors: a human-readable `message`
+/// plus enough contextual coordinates (the type/directive we were reading
+/// and the vector index/offset we were at) to point back at the broken
+/// element without needing to re-run the whole decode under a debugger.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaReadError {
+    pub message: String,
+    pub type_name: Option<StringKey>,
+    pub directive_name: Option<StringKey>,
+    pub index: Option<usize>,
+}
+
+impl SchemaReadError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            type_name: None,
+            directive_name: None,
+            index: None,
+        }
+    }
+
+    /// Returns `self` with `type_name` set, unless it is already set by an
+    /// inner call closer to the actual failure.
+    pub fn with_type_name(mut self, type_name: StringKey) -> Self {
+        if self.type_name.is_none() {
+            self.type_name = Some(type_name);
+        }
+        self
+    }
+
+    /// Returns `self` with `directive_name` set, unless it is already set by
+    /// an inner call closer to the actual failure.
+    pub fn with_directive_name(mut self, directive_name: StringKey) -> Self {
+        if self.directive_name.is_none() {
+            self.directive_name = Some(directive_name);
+        }
+        self
+    }
+
+    /// Returns `self` with `index` set, unless it is already set by an inner
+    /// call closer to the actual failure.
+    pub fn with_index(mut self, index: usize) -> Self {
+        if self.index.is_none() {
+            self.index = Some(index);
+        }
+        self
+    }
+
+    /// A `SchemaReadError` for a FlatBuffer table field that was expected to
+    /// be present but was missing or malformed, e.g. `field.type_()` failing
+    /// to decode.
+    pub fn missing_field(field: &str) -> Self {
+        Self::new(format!("{} missing", field))
+    }
+
+    /// A `SchemaReadError` for an id that indexes out of bounds of the
+    /// corresponding FlatBuffer vector, e.g. a `scalar_id` that has no
+    /// matching entry in `FBSchema::scalars`.
+    pub fn id_out_of_range(vector_name: &str, id: u32) -> Self {
+        Self::new(format!("{} {} out of range", vector_name, id))
+    }
+}
+
+impl fmt::Display for SchemaReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        if let Some(type_name) = self.type_name {
+            write!(f, " (while reading type `{}`", type_name)?;
+            if let Some(directive_name) = self.directive_name {
+                write!(f, ", directive `{}`", directive_name)?;
+            }
+            if let Some(index) = self.index {
+                write!(f, ", index {}", index)?;
+            }
+            write!(f, ")")?;
+        } else if let Some(directive_name) = self.directive_name {
+            write!(f, " (while reading directive `{}`", directive_name)?;
+            if let Some(index) = self.index {
+                write!(f, ", index {}", index)?;
+            }
+            write!(f, ")")?;
+        } else if let Some(index) = self.index {
+            write!(f, " (index {})", index)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for SchemaReadError {}
@@ -6,6 +6,7 @@
  */
 
 use crate::definitions::{Argument, Directive, *};
+use crate::errors::SchemaReadError;
 use crate::graphqlschema_generated::graphqlschema::*;
 use common::Span;
 use flatbuffers::*;
@@ -25,99 +26,140 @@ pub struct FlatBufferSchema<'fb> {
 }
 
 impl<'fb> FlatBufferSchema<'fb> {
-    pub fn build(bytes: &'fb [u8], schema: Schema) -> Self {
+    pub fn build(bytes: &'fb [u8], schema: Schema) -> Result<Self, SchemaReadError> {
         let fb_schema: FBSchema<'fb> = get_root_as_fbschema(bytes);
-        Self {
+        Ok(Self {
             fb_schema,
-            types: fb_schema.types().unwrap(),
+            types: fb_schema
+                .types()
+                .ok_or_else(|| SchemaReadError::missing_field("schema.types()"))?,
             schema,
-            directives: fb_schema.directives().unwrap(),
-        }
+            directives: fb_schema
+                .directives()
+                .ok_or_else(|| SchemaReadError::missing_field("schema.directives()"))?,
+        })
     }
 
-    pub fn get_type(&mut self, type_name: StringKey) -> Option<Type> {
+    pub fn get_type(&mut self, type_name: StringKey) -> Result<Option<Type>, SchemaReadError> {
         if !self.schema.has_type(type_name) {
             return self.read_type(type_name);
         }
-        self.schema.get_type(type_name)
+        Ok(self.schema.get_type(type_name))
     }
 
-    pub fn has_type(&mut self, type_name: StringKey) -> bool {
-        self.get_type(type_name).is_some()
+    pub fn has_type(&mut self, type_name: StringKey) -> Result<bool, SchemaReadError> {
+        Ok(self.get_type(type_name)?.is_some())
     }
 
-    pub fn get_directive(&mut self, directive_name: StringKey) -> Option<&Directive> {
+    pub fn get_directive(
+        &mut self,
+        directive_name: StringKey,
+    ) -> Result<Option<&Directive>, SchemaReadError> {
         if self.schema.get_directive(directive_name).is_none() {
             return self.read_directive(directive_name);
         };
-        self.schema.get_directive(directive_name)
+        Ok(self.schema.get_directive(directive_name))
     }
 
     pub fn snapshot_print(self) -> String {
         self.schema.snapshot_print()
     }
 
-    fn read_directive(&mut self, name: StringKey) -> Option<&Directive> {
+    fn read_directive(
+        &mut self,
+        name: StringKey,
+    ) -> Result<Option<&Directive>, SchemaReadError> {
         let mut start = 0;
         let mut end = self.directives.len();
-        while start <= end {
-            let mid = (start + end) / 2;
+        while start < end {
+            let mid = start + (end - start) / 2;
             let cmp = self
                 .directives
                 .get(mid)
                 .key_compare_with_value(name.lookup());
             if cmp == ::std::cmp::Ordering::Equal {
-                let directive = self.directives.get(mid).value()?;
-                return Some(self.parse_directive(directive)?);
+                let directive = self
+                    .directives
+                    .get(mid)
+                    .value()
+                    .ok_or_else(|| SchemaReadError::missing_field("directive_map.value()"))?;
+                return self
+                    .parse_directive(directive)
+                    .map(Some)
+                    .map_err(|err| err.with_directive_name(name).with_index(mid));
             } else if cmp == ::std::cmp::Ordering::Less {
                 start = mid + 1;
             } else {
-                end = mid - 1;
+                end = mid;
             }
         }
-        None
+        Ok(None)
     }
 
-    fn parse_directive(&mut self, directive: FBDirective<'fb>) -> Option<&Directive> {
+    fn parse_directive(
+        &mut self,
+        directive: FBDirective<'fb>,
+    ) -> Result<&Directive, SchemaReadError> {
+        let name = directive
+            .name()
+            .ok_or_else(|| SchemaReadError::missing_field("directive.name()"))?
+            .intern();
         let locations = directive
-            .locations()?
+            .locations()
+            .ok_or_else(|| SchemaReadError::missing_field("directive.locations()"))?
             .iter()
             .map(get_mapped_location)
             .collect::<Vec<_>>();
+        let arguments = directive
+            .arguments()
+            .ok_or_else(|| SchemaReadError::missing_field("directive.arguments()"))
+            .and_then(|arguments| self.parse_arguments(arguments))
+            .map_err(|err| err.with_directive_name(name))?;
         let parsed_directive = Directive {
-            name: directive.name()?.intern(),
+            name,
             is_extension: directive.is_extension(),
-            arguments: self.parse_arguments(directive.arguments()?)?,
+            arguments,
             locations,
             repeatable: directive.repeatable(),
         };
-        self.schema.add_directive(parsed_directive).unwrap();
-        self.schema.get_directive(directive.name()?.intern())
+        self.schema
+            .add_directive(parsed_directive)
+            .map_err(|err| SchemaReadError::new(err.to_string()).with_directive_name(name))?;
+        self.schema
+            .get_directive(name)
+            .ok_or_else(|| SchemaReadError::new("directive vanished after insertion"))
     }
 
-    fn read_type(&mut self, type_name: StringKey) -> Option<Type> {
+    fn read_type(&mut self, type_name: StringKey) -> Result<Option<Type>, SchemaReadError> {
         let mut start = 0;
         let mut end = self.types.len();
-        while start <= end {
-            let mid = (start + end) / 2;
+        while start < end {
+            let mid = start + (end - start) / 2;
             let cmp = self
                 .types
                 .get(mid)
                 .key_compare_with_value(type_name.lookup());
             if cmp == ::std::cmp::Ordering::Equal {
-                let type_ = self.types.get(mid).value()?;
-                return Some(self.parse_type(type_)?);
+                let type_ = self
+                    .types
+                    .get(mid)
+                    .value()
+                    .ok_or_else(|| SchemaReadError::missing_field("type_map.value()"))?;
+                return self
+                    .parse_type(type_)
+                    .map(Some)
+                    .map_err(|err| err.with_type_name(type_name).with_index(mid));
             } else if cmp == ::std::cmp::Ordering::Less {
                 start = mid + 1;
             } else {
-                end = mid - 1;
+                end = mid;
             }
         }
-        None
+        Ok(None)
     }
 
-    fn parse_type(&mut self, type_: FBType<'_>) -> Option<Type> {
-        Some(match type_.kind() {
+    fn parse_type(&mut self, type_: FBType<'_>) -> Result<Type, SchemaReadError> {
+        Ok(match type_.kind() {
             FBTypeKind::Scalar => self.parse_scalar(type_.scalar_id())?,
             FBTypeKind::InputObject => self.parse_input_object(type_.input_object_id())?,
             FBTypeKind::Enum => self.parse_enum(type_.enum_id())?,
@@ -127,161 +169,411 @@ impl<'fb> FlatBufferSchema<'fb> {
         })
     }
 
-    fn parse_scalar(&mut self, id: u32) -> Option<Type> {
-        let scalar = self.fb_schema.scalars()?.get(id.try_into().unwrap());
+    fn parse_scalar(&mut self, id: u32) -> Result<Type, SchemaReadError> {
+        let scalar = self
+            .fb_schema
+            .scalars()
+            .ok_or_else(|| SchemaReadError::missing_field("schema.scalars()"))?
+            .get(id.try_into().map_err(|_| SchemaReadError::id_out_of_range("scalar_id", id))?);
+        let name = scalar
+            .name()
+            .ok_or_else(|| SchemaReadError::missing_field("scalar.name()"))?
+            .to_string()
+            .intern();
+        let directives = self
+            .parse_directive_values(
+                scalar
+                    .directives()
+                    .ok_or_else(|| SchemaReadError::missing_field("scalar.directives()"))?,
+            )
+            .map_err(|err| err.with_type_name(name))?;
         let parsed_scalar = Scalar {
-            name: scalar.name()?.to_string().intern(),
+            name,
             is_extension: scalar.is_extension(),
-            directives: self.parse_directive_values(scalar.directives()?)?,
+            directives,
         };
-        Some(Type::Scalar(self.schema.add_scalar(parsed_scalar).unwrap()))
+        Ok(Type::Scalar(
+            self.schema
+                .add_scalar(parsed_scalar)
+                .map_err(|err| SchemaReadError::new(err.to_string()).with_type_name(name))?,
+        ))
     }
 
-    fn parse_input_object(&mut self, id: u32) -> Option<Type> {
-        let input_object = self.fb_schema.input_objects()?.get(id.try_into().unwrap());
+    fn parse_input_object(&mut self, id: u32) -> Result<Type, SchemaReadError> {
+        let input_object = self
+            .fb_schema
+            .input_objects()
+            .ok_or_else(|| SchemaReadError::missing_field("schema.input_objects()"))?
+            .get(
+                id.try_into()
+                    .map_err(|_| SchemaReadError::id_out_of_range("input_object_id", id))?,
+            );
+        let name = input_object
+            .name()
+            .ok_or_else(|| SchemaReadError::missing_field("input_object.name()"))?
+            .to_string()
+            .intern();
+        let fields = self
+            .parse_arguments(
+                input_object
+                    .fields()
+                    .ok_or_else(|| SchemaReadError::missing_field("input_object.fields()"))?,
+            )
+            .map_err(|err| err.with_type_name(name))?;
+        let directives = self
+            .parse_directive_values(
+                input_object
+                    .directives()
+                    .ok_or_else(|| SchemaReadError::missing_field("input_object.directives()"))?,
+            )
+            .map_err(|err| err.with_type_name(name))?;
         let parsed_input_object = InputObject {
-            name: input_object.name()?.to_string().intern(),
-            fields: self.parse_arguments(input_object.fields()?)?,
-            directives: self.parse_directive_values(input_object.directives()?)?,
+            name,
+            fields,
+            directives,
         };
-        Some(Type::InputObject(
-            self.schema.add_input_object(parsed_input_object).unwrap(),
+        Ok(Type::InputObject(
+            self.schema
+                .add_input_object(parsed_input_object)
+                .map_err(|err| SchemaReadError::new(err.to_string()).with_type_name(name))?,
         ))
     }
 
-    fn parse_enum(&mut self, id: u32) -> Option<Type> {
-        let enum_ = self.fb_schema.enums()?.get(id.try_into().unwrap());
+    fn parse_enum(&mut self, id: u32) -> Result<Type, SchemaReadError> {
+        let enum_ = self
+            .fb_schema
+            .enums()
+            .ok_or_else(|| SchemaReadError::missing_field("schema.enums()"))?
+            .get(id.try_into().map_err(|_| SchemaReadError::id_out_of_range("enum_id", id))?);
+        let name = enum_
+            .name()
+            .ok_or_else(|| SchemaReadError::missing_field("enum.name()"))?
+            .to_string()
+            .intern();
+        let values = self
+            .parse_enum_values(
+                enum_
+                    .values()
+                    .ok_or_else(|| SchemaReadError::missing_field("enum.values()"))?,
+            )
+            .map_err(|err| err.with_type_name(name))?;
+        let directives = self
+            .parse_directive_values(
+                enum_
+                    .directives()
+                    .ok_or_else(|| SchemaReadError::missing_field("enum.directives()"))?,
+            )
+            .map_err(|err| err.with_type_name(name))?;
         let parsed_enum = Enum {
-            name: enum_.name()?.to_string().intern(),
+            name,
             is_extension: enum_.is_extension(),
-            values: self.parse_enum_values(enum_.values()?)?,
-            directives: self.parse_directive_values(enum_.directives()?)?,
+            values,
+            directives,
         };
-        Some(Type::Enum(self.schema.add_enum(parsed_enum).unwrap()))
+        Ok(Type::Enum(
+            self.schema
+                .add_enum(parsed_enum)
+                .map_err(|err| SchemaReadError::new(err.to_string()).with_type_name(name))?,
+        ))
     }
 
-    fn parse_object(&mut self, id: u32) -> Option<Type> {
-        let object = self.fb_schema.objects()?.get(id.try_into().unwrap());
+    fn parse_object(&mut self, id: u32) -> Result<Type, SchemaReadError> {
+        let object = self
+            .fb_schema
+            .objects()
+            .ok_or_else(|| SchemaReadError::missing_field("schema.objects()"))?
+            .get(id.try_into().map_err(|_| SchemaReadError::id_out_of_range("object_id", id))?);
+        let name = object
+            .name()
+            .ok_or_else(|| SchemaReadError::missing_field("object.name()"))?
+            .intern();
+        let directives = self
+            .parse_directive_values(
+                object
+                    .directives()
+                    .ok_or_else(|| SchemaReadError::missing_field("object.directives()"))?,
+            )
+            .map_err(|err| err.with_type_name(name))?;
         let parsed_object = Object {
-            name: object.name()?.intern(),
+            name,
             is_extension: object.is_extension(),
             fields: vec![],
             interfaces: vec![],
-            directives: self.parse_directive_values(object.directives()?)?,
+            directives,
         };
-        let new_id = self.schema.add_object(parsed_object).unwrap();
-        for field_id in object.fields()? {
-            let field = self.parse_field(field_id)?;
-            self.schema.add_field_to_object(new_id, field).unwrap();
+        let new_id = self
+            .schema
+            .add_object(parsed_object)
+            .map_err(|err| SchemaReadError::new(err.to_string()).with_type_name(name))?;
+        for field_id in object
+            .fields()
+            .ok_or_else(|| SchemaReadError::missing_field("object.fields()"))?
+        {
+            let field = self
+                .parse_field(field_id)
+                .map_err(|err| err.with_type_name(name))?;
+            self.schema
+                .add_field_to_object(new_id, field)
+                .map_err(|err| SchemaReadError::new(err.to_string()).with_type_name(name))?;
         }
-        for interface_id in object.interfaces()? {
-            let interface = self.get_type(
-                self.fb_schema
-                    .interfaces()?
-                    .get(interface_id.try_into().unwrap())
-                    .name()?
-                    .intern(),
-            )?;
+        for interface_id in object
+            .interfaces()
+            .ok_or_else(|| SchemaReadError::missing_field("object.interfaces()"))?
+        {
+            let interface_name = self
+                .fb_schema
+                .interfaces()
+                .ok_or_else(|| SchemaReadError::missing_field("schema.interfaces()"))?
+                .get(
+                    interface_id
+                        .try_into()
+                        .map_err(|_| SchemaReadError::id_out_of_range("interface_id", interface_id))?,
+                )
+                .name()
+                .ok_or_else(|| SchemaReadError::missing_field("interface.name()"))?
+                .intern();
+            let interface = self
+                .get_type(interface_name)?
+                .ok_or_else(|| SchemaReadError::new("interface referenced by object not found"))
+                .map_err(|err| err.with_type_name(name))?;
             self.schema
-                .add_interface_to_object(new_id, interface.get_interface_id()?)
-                .unwrap();
+                .add_interface_to_object(
+                    new_id,
+                    interface
+                        .get_interface_id()
+                        .ok_or_else(|| SchemaReadError::new("expected an interface"))?,
+                )
+                .map_err(|err| SchemaReadError::new(err.to_string()).with_type_name(name))?;
         }
-        Some(Type::Object(new_id))
+        Ok(Type::Object(new_id))
     }
 
-    fn parse_interface(&mut self, id: u32) -> Option<Type> {
-        let interface = self.fb_schema.interfaces()?.get(id.try_into().unwrap());
+    fn parse_interface(&mut self, id: u32) -> Result<Type, SchemaReadError> {
+        let interface = self
+            .fb_schema
+            .interfaces()
+            .ok_or_else(|| SchemaReadError::missing_field("schema.interfaces()"))?
+            .get(
+                id.try_into()
+                    .map_err(|_| SchemaReadError::id_out_of_range("interface_id", id))?,
+            );
+        let name = interface
+            .name()
+            .ok_or_else(|| SchemaReadError::missing_field("interface.name()"))?
+            .intern();
+        let directives = self
+            .parse_directive_values(
+                interface
+                    .directives()
+                    .ok_or_else(|| SchemaReadError::missing_field("interface.directives()"))?,
+            )
+            .map_err(|err| err.with_type_name(name))?;
         let parsed_interface = Interface {
-            name: interface.name()?.intern(),
+            name,
             is_extension: interface.is_extension(),
             implementing_objects: vec![],
             fields: vec![],
-            directives: self.parse_directive_values(interface.directives()?)?,
+            directives,
             interfaces: vec![],
         };
-        let new_id = self.schema.add_interface(parsed_interface).unwrap();
-        for field_id in interface.fields()? {
-            let field = self.parse_field(field_id)?;
-            self.schema.add_field_to_interface(new_id, field).unwrap();
+        let new_id = self
+            .schema
+            .add_interface(parsed_interface)
+            .map_err(|err| SchemaReadError::new(err.to_string()).with_type_name(name))?;
+        for field_id in interface
+            .fields()
+            .ok_or_else(|| SchemaReadError::missing_field("interface.fields()"))?
+        {
+            let field = self
+                .parse_field(field_id)
+                .map_err(|err| err.with_type_name(name))?;
+            self.schema
+                .add_field_to_interface(new_id, field)
+                .map_err(|err| SchemaReadError::new(err.to_string()).with_type_name(name))?;
         }
-        for interface_id in interface.interfaces()? {
-            let interface = self.get_type(
-                self.fb_schema
-                    .interfaces()?
-                    .get(interface_id.try_into().unwrap())
-                    .name()?
-                    .intern(),
-            )?;
+        for interface_id in interface
+            .interfaces()
+            .ok_or_else(|| SchemaReadError::missing_field("interface.interfaces()"))?
+        {
+            let parent_name = self
+                .fb_schema
+                .interfaces()
+                .ok_or_else(|| SchemaReadError::missing_field("schema.interfaces()"))?
+                .get(
+                    interface_id
+                        .try_into()
+                        .map_err(|_| SchemaReadError::id_out_of_range("interface_id", interface_id))?,
+                )
+                .name()
+                .ok_or_else(|| SchemaReadError::missing_field("interface.name()"))?
+                .intern();
+            let parent = self
+                .get_type(parent_name)?
+                .ok_or_else(|| SchemaReadError::new("parent interface not found"))
+                .map_err(|err| err.with_type_name(name))?;
             self.schema
-                .add_parent_interface_to_interface(new_id, interface.get_interface_id()?)
-                .unwrap();
+                .add_parent_interface_to_interface(
+                    new_id,
+                    parent
+                        .get_interface_id()
+                        .ok_or_else(|| SchemaReadError::new("expected an interface"))?,
+                )
+                .map_err(|err| SchemaReadError::new(err.to_string()).with_type_name(name))?;
         }
-        for object_id in interface.implementing_objects()? {
-            let object = self.get_type(
-                self.fb_schema
-                    .objects()?
-                    .get(object_id.try_into().unwrap())
-                    .name()?
-                    .intern(),
-            )?;
+        for object_id in interface
+            .implementing_objects()
+            .ok_or_else(|| SchemaReadError::missing_field("interface.implementing_objects()"))?
+        {
+            let object_name = self
+                .fb_schema
+                .objects()
+                .ok_or_else(|| SchemaReadError::missing_field("schema.objects()"))?
+                .get(
+                    object_id
+                        .try_into()
+                        .map_err(|_| SchemaReadError::id_out_of_range("object_id", object_id))?,
+                )
+                .name()
+                .ok_or_else(|| SchemaReadError::missing_field("object.name()"))?
+                .intern();
+            let object = self
+                .get_type(object_name)?
+                .ok_or_else(|| SchemaReadError::new("implementing object not found"))
+                .map_err(|err| err.with_type_name(name))?;
             self.schema
-                .add_implementing_object_to_interface(new_id, object.get_object_id()?)
-                .unwrap();
+                .add_implementing_object_to_interface(
+                    new_id,
+                    object
+                        .get_object_id()
+                        .ok_or_else(|| SchemaReadError::new("expected an object"))?,
+                )
+                .map_err(|err| SchemaReadError::new(err.to_string()).with_type_name(name))?;
         }
-        Some(Type::Interface(new_id))
+        Ok(Type::Interface(new_id))
     }
 
-    fn parse_union(&mut self, id: u32) -> Option<Type> {
-        let union = self.fb_schema.unions()?.get(id.try_into().unwrap());
+    fn parse_union(&mut self, id: u32) -> Result<Type, SchemaReadError> {
+        let union = self
+            .fb_schema
+            .unions()
+            .ok_or_else(|| SchemaReadError::missing_field("schema.unions()"))?
+            .get(id.try_into().map_err(|_| SchemaReadError::id_out_of_range("union_id", id))?);
+        let name = union
+            .name()
+            .ok_or_else(|| SchemaReadError::missing_field("union.name()"))?
+            .intern();
+        let directives = self
+            .parse_directive_values(
+                union
+                    .directives()
+                    .ok_or_else(|| SchemaReadError::missing_field("union.directives()"))?,
+            )
+            .map_err(|err| err.with_type_name(name))?;
         let parsed_union = Union {
-            name: union.name()?.intern(),
+            name,
             is_extension: union.is_extension(),
             members: vec![],
-            directives: self.parse_directive_values(union.directives()?)?,
+            directives,
         };
-        let new_id = self.schema.add_union(parsed_union).unwrap();
-        for object_id in union.members()? {
-            let object = self.get_type(
-                self.fb_schema
-                    .objects()?
-                    .get(object_id.try_into().unwrap())
-                    .name()?
-                    .intern(),
-            )?;
+        let new_id = self
+            .schema
+            .add_union(parsed_union)
+            .map_err(|err| SchemaReadError::new(err.to_string()).with_type_name(name))?;
+        for object_id in union
+            .members()
+            .ok_or_else(|| SchemaReadError::missing_field("union.members()"))?
+        {
+            let object_name = self
+                .fb_schema
+                .objects()
+                .ok_or_else(|| SchemaReadError::missing_field("schema.objects()"))?
+                .get(
+                    object_id
+                        .try_into()
+                        .map_err(|_| SchemaReadError::id_out_of_range("object_id", object_id))?,
+                )
+                .name()
+                .ok_or_else(|| SchemaReadError::missing_field("object.name()"))?
+                .intern();
+            let object = self
+                .get_type(object_name)?
+                .ok_or_else(|| SchemaReadError::new("union member not found"))
+                .map_err(|err| err.with_type_name(name))?;
             self.schema
-                .add_member_to_union(new_id, object.get_object_id()?)
-                .unwrap();
+                .add_member_to_union(
+                    new_id,
+                    object
+                        .get_object_id()
+                        .ok_or_else(|| SchemaReadError::new("expected an object"))?,
+                )
+                .map_err(|err| SchemaReadError::new(err.to_string()).with_type_name(name))?;
         }
-        Some(Type::Union(new_id))
+        Ok(Type::Union(new_id))
     }
 
-    fn parse_field(&mut self, id: u32) -> Option<FieldID> {
-        let field = self.fb_schema.fields()?.get(id.try_into().unwrap());
+    fn parse_field(&mut self, id: u32) -> Result<FieldID, SchemaReadError> {
+        let field = self
+            .fb_schema
+            .fields()
+            .ok_or_else(|| SchemaReadError::missing_field("schema.fields()"))?
+            .get(id.try_into().map_err(|_| SchemaReadError::id_out_of_range("field_id", id))?);
+        let parent_type_fb = field
+            .parent_type()
+            .ok_or_else(|| SchemaReadError::missing_field("field.parent_type()"))?;
+        let parent_type_name = self.get_fbtype_name(&parent_type_fb)?;
         let parsed_field = Field {
-            name: field.name()?.intern(),
+            name: field
+                .name()
+                .ok_or_else(|| SchemaReadError::missing_field("field.name()"))?
+                .intern(),
             is_extension: field.is_extension(),
-            arguments: self.parse_arguments(field.arguments()?)?,
-            type_: self.parse_type_reference(field.type_()?)?,
-            directives: self.parse_directive_values(field.directives()?)?,
-            parent_type: self.get_type(self.get_fbtype_name(&field.parent_type()?)),
+            arguments: self.parse_arguments(
+                field
+                    .arguments()
+                    .ok_or_else(|| SchemaReadError::missing_field("field.arguments()"))?,
+            )?,
+            type_: self.parse_type_reference(
+                field
+                    .type_()
+                    .ok_or_else(|| SchemaReadError::missing_field("field.type_()"))?,
+            )?,
+            directives: self.parse_directive_values(
+                field
+                    .directives()
+                    .ok_or_else(|| SchemaReadError::missing_field("field.directives()"))?,
+            )?,
+            parent_type: self.get_type(parent_type_name)?,
         };
-        Some(self.schema.add_field(parsed_field).unwrap())
+        self.schema
+            .add_field(parsed_field)
+            .map_err(|err| SchemaReadError::new(err.to_string()))
     }
 
     fn parse_enum_values(
         &self,
         values: Vector<'_, ForwardsUOffset<FBEnumValue<'_>>>,
-    ) -> Option<Vec<EnumValue>> {
+    ) -> Result<Vec<EnumValue>, SchemaReadError> {
         values
             .iter()
-            .map(|value| self.parse_enum_value(value))
-            .collect::<Option<Vec<_>>>()
+            .enumerate()
+            .map(|(index, value)| {
+                self.parse_enum_value(value)
+                    .map_err(|err| err.with_index(index))
+            })
+            .collect()
     }
 
-    fn parse_enum_value(&self, value: FBEnumValue<'fb>) -> Option<EnumValue> {
-        let directives = self.parse_directive_values(value.directives()?)?;
-        Some(EnumValue {
-            value: value.value()?.intern(),
+    fn parse_enum_value(&self, value: FBEnumValue<'fb>) -> Result<EnumValue, SchemaReadError> {
+        let directives = self.parse_directive_values(
+            value
+                .directives()
+                .ok_or_else(|| SchemaReadError::missing_field("enum_value.directives()"))?,
+        )?;
+        Ok(EnumValue {
+            value: value
+                .value()
+                .ok_or_else(|| SchemaReadError::missing_field("enum_value.value()"))?
+                .intern(),
             directives,
         })
     }
@@ -289,232 +581,528 @@ impl<'fb> FlatBufferSchema<'fb> {
     fn parse_arguments(
         &mut self,
         arguments: Vector<'fb, ForwardsUOffset<FBArgument<'_>>>,
-    ) -> Option<ArgumentDefinitions> {
+    ) -> Result<ArgumentDefinitions, SchemaReadError> {
         let items = arguments
             .iter()
-            .map(|argument| self.parse_argument(argument))
-            .collect::<Option<Vec<_>>>();
-        Some(ArgumentDefinitions::new(items?))
+            .enumerate()
+            .map(|(index, argument)| {
+                self.parse_argument(argument)
+                    .map_err(|err| err.with_index(index))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ArgumentDefinitions::new(items))
     }
 
-    fn parse_argument(&mut self, argument: FBArgument<'fb>) -> Option<Argument> {
-        Some(Argument {
-            name: argument.name().unwrap().intern(),
-            default_value: match argument.value() {
-                Some(value) => Some(self.parse_const_value(value)?),
-                _ => None,
-            },
-            type_: self.parse_type_reference(argument.type_()?)?,
+    fn parse_argument(&mut self, argument: FBArgument<'fb>) -> Result<Argument, SchemaReadError> {
+        let name = argument
+            .name()
+            .ok_or_else(|| SchemaReadError::missing_field("argument.name()"))?
+            .intern();
+        let type_ = self.parse_type_reference(
+            argument
+                .type_()
+                .ok_or_else(|| SchemaReadError::missing_field("argument.type_()"))?,
+        )?;
+        let default_value = match argument.value() {
+            Some(value) => {
+                let const_value = self.parse_const_value(value)?;
+                self.validate_const_value(&const_value, &type_).map_err(|err| {
+                    SchemaReadError::new(format!(
+                        "default value for argument `{}`: {}",
+                        name, err.message
+                    ))
+                })?;
+                Some(const_value)
+            }
+            None => None,
+        };
+        Ok(Argument {
+            name,
+            default_value,
+            type_,
         })
     }
 
+    /// Checks a const value against the `TypeReference` it's a default for,
+    /// following async-graphql's rule that a const position must never hold
+    /// a variable -- `ConstantValue` has no variable variant, so that's
+    /// enforced by the type system rather than at runtime here. This
+    /// catches the cases a bad `parse()` used to paper over: a string
+    /// default on an `Int` argument, an enum value not in the enum's
+    /// `values`, a non-null field with a `Null` default, and a list default
+    /// against a non-list type.
+    fn validate_const_value(
+        &self,
+        value: &ConstantValue,
+        type_: &TypeReference,
+    ) -> Result<(), SchemaReadError> {
+        if let ConstantValue::Null(_) = value {
+            return if matches!(type_, TypeReference::NonNull(_)) {
+                Err(SchemaReadError::new(
+                    "a non-null type cannot have a `null` default value",
+                ))
+            } else {
+                Ok(())
+            };
+        }
+        match type_ {
+            TypeReference::NonNull(inner) => self.validate_const_value(value, inner),
+            TypeReference::List(inner) => match value {
+                ConstantValue::List(list) => {
+                    for (index, item) in list.items.iter().enumerate() {
+                        self.validate_const_value(item, inner)
+                            .map_err(|err| err.with_index(index))?;
+                    }
+                    Ok(())
+                }
+                // GraphQL coerces a single non-list value into a one-item
+                // list, e.g. `arg: [Int] = 5` and `arg: [String!] = "x"` are
+                // both spec-valid -- so a scalar default is validated
+                // against the list's inner type, not rejected outright.
+                _ => self.validate_const_value(value, inner),
+            },
+            TypeReference::Named(named_type) => self.validate_named_const_value(value, *named_type),
+        }
+    }
+
+    fn validate_named_const_value(
+        &self,
+        value: &ConstantValue,
+        named_type: Type,
+    ) -> Result<(), SchemaReadError> {
+        match named_type {
+            Type::Scalar(id) => {
+                let scalar = self.schema.scalar(id);
+                validate_scalar_const_value(value, scalar.name)
+            }
+            Type::Enum(id) => match value {
+                ConstantValue::Enum(node) => {
+                    let enum_ = self.schema.enum_(id);
+                    if enum_.values.iter().any(|v| v.value == node.value) {
+                        Ok(())
+                    } else {
+                        Err(SchemaReadError::new(format!(
+                            "`{}` is not a value of enum `{}`",
+                            node.value, enum_.name
+                        )))
+                    }
+                }
+                _ => Err(SchemaReadError::new(format!(
+                    "expected an enum value for enum `{}`",
+                    self.schema.enum_(id).name
+                ))),
+            },
+            Type::InputObject(id) => match value {
+                ConstantValue::Object(object) => {
+                    let input_object = self.schema.input_object(id);
+                    for field in input_object.fields.iter() {
+                        let provided = object.items.iter().find(|item| item.name.value == field.name);
+                        match provided {
+                            Some(item) => self
+                                .validate_const_value(&item.value, &field.type_)
+                                .map_err(|err| SchemaReadError {
+                                    message: format!("field `{}`: {}", field.name, err.message),
+                                    ..err
+                                })?,
+                            None if matches!(field.type_, TypeReference::NonNull(_))
+                                && field.default_value.is_none() =>
+                            {
+                                return Err(SchemaReadError::new(format!(
+                                    "missing required field `{}` on input object `{}`",
+                                    field.name, input_object.name
+                                )));
+                            }
+                            None => {}
+                        }
+                    }
+                    Ok(())
+                }
+                _ => Err(SchemaReadError::new(format!(
+                    "expected an object default value for input object `{}`",
+                    self.schema.input_object(id).name
+                ))),
+            },
+            Type::Object(_) | Type::Interface(_) | Type::Union(_) => Err(SchemaReadError::new(
+                "object, interface, and union types cannot have default values",
+            )),
+        }
+    }
+
     fn parse_type_reference(
         &mut self,
         type_reference: FBTypeReference<'fb>,
-    ) -> Option<TypeReference> {
-        Some(match type_reference.kind() {
+    ) -> Result<TypeReference, SchemaReadError> {
+        Ok(match type_reference.kind() {
             FBTypeReferenceKind::Named => {
-                let type_name = self.get_fbtype_name(&type_reference.named()?);
-                TypeReference::Named(self.get_type(type_name).unwrap())
-            }
-            FBTypeReferenceKind::NonNull => {
-                TypeReference::NonNull(Box::new(self.parse_type_reference(type_reference.null()?)?))
-            }
-            FBTypeReferenceKind::List => {
-                TypeReference::List(Box::new(self.parse_type_reference(type_reference.list()?)?))
+                let fb_type = type_reference
+                    .named()
+                    .ok_or_else(|| SchemaReadError::missing_field("type_reference.named()"))?;
+                let type_name = self.get_fbtype_name(&fb_type)?;
+                TypeReference::Named(
+                    self.get_type(type_name)?
+                        .ok_or_else(|| SchemaReadError::new("named type not found"))?,
+                )
             }
+            FBTypeReferenceKind::NonNull => TypeReference::NonNull(Box::new(
+                self.parse_type_reference(
+                    type_reference
+                        .null()
+                        .ok_or_else(|| SchemaReadError::missing_field("type_reference.null()"))?,
+                )?,
+            )),
+            FBTypeReferenceKind::List => TypeReference::List(Box::new(self.parse_type_reference(
+                type_reference
+                    .list()
+                    .ok_or_else(|| SchemaReadError::missing_field("type_reference.list()"))?,
+            )?)),
         })
     }
 
     fn parse_directive_values(
         &self,
         directives: Vector<'_, ForwardsUOffset<FBDirectiveValue<'_>>>,
-    ) -> Option<Vec<DirectiveValue>> {
+    ) -> Result<Vec<DirectiveValue>, SchemaReadError> {
         directives
             .iter()
-            .map(|directive| self.parse_directive_value(directive))
-            .collect::<Option<Vec<_>>>()
+            .enumerate()
+            .map(|(index, directive)| {
+                self.parse_directive_value(directive)
+                    .map_err(|err| err.with_index(index))
+            })
+            .collect()
     }
 
-    fn parse_directive_value(&self, directive: FBDirectiveValue<'fb>) -> Option<DirectiveValue> {
-        let arguments = self.parse_argument_values(directive.arguments()?)?;
-        Some(DirectiveValue {
-            name: directive.name()?.intern(),
-            arguments,
-        })
+    fn parse_directive_value(
+        &self,
+        directive: FBDirectiveValue<'fb>,
+    ) -> Result<DirectiveValue, SchemaReadError> {
+        let name = directive
+            .name()
+            .ok_or_else(|| SchemaReadError::missing_field("directive_value.name()"))?
+            .intern();
+        // Best-effort: a directive value whose definition isn't registered
+        // (yet, or at all) is parsed as-is, with no argument validation.
+        let argument_definitions = self.schema.get_directive(name).map(|d| &d.arguments);
+        let arguments = self
+            .parse_argument_values(
+                directive
+                    .arguments()
+                    .ok_or_else(|| SchemaReadError::missing_field("directive_value.arguments()"))?,
+                argument_definitions,
+            )
+            .map_err(|err| err.with_directive_name(name))?;
+        Ok(DirectiveValue { name, arguments })
     }
 
     fn parse_argument_values(
         &self,
         arguments: Vector<'_, ForwardsUOffset<FBArgumentValue<'_>>>,
-    ) -> Option<Vec<ArgumentValue>> {
+        argument_definitions: Option<&ArgumentDefinitions>,
+    ) -> Result<Vec<ArgumentValue>, SchemaReadError> {
         arguments
             .iter()
-            .map(|argument| self.parse_argument_value(argument))
-            .collect::<Option<Vec<_>>>()
+            .enumerate()
+            .map(|(index, argument)| {
+                self.parse_argument_value(argument, argument_definitions)
+                    .map_err(|err| err.with_index(index))
+            })
+            .collect()
     }
 
-    fn parse_argument_value(&self, argument: FBArgumentValue<'fb>) -> Option<ArgumentValue> {
-        Some(ArgumentValue {
-            name: argument.name()?.intern(),
-            value: self.parse_const_value(argument.value()?)?,
-        })
+    fn parse_argument_value(
+        &self,
+        argument: FBArgumentValue<'fb>,
+        argument_definitions: Option<&ArgumentDefinitions>,
+    ) -> Result<ArgumentValue, SchemaReadError> {
+        let name = argument
+            .name()
+            .ok_or_else(|| SchemaReadError::missing_field("argument_value.name()"))?
+            .intern();
+        let value = self.parse_const_value(
+            argument
+                .value()
+                .ok_or_else(|| SchemaReadError::missing_field("argument_value.value()"))?,
+        )?;
+        if let Some(argument_definition) = argument_definitions
+            .and_then(|definitions| definitions.iter().find(|arg| arg.name == name))
+        {
+            self.validate_const_value(&value, &argument_definition.type_)
+                .map_err(|err| {
+                    SchemaReadError::new(format!("argument `{}`: {}", name, err.message))
+                })?;
+        }
+        Ok(ArgumentValue { name, value })
     }
 
-    fn parse_const_value(&self, value: FBConstValue<'fb>) -> Option<ConstantValue> {
-        Some(match value.kind() {
-            FBConstValueKind::Null => ConstantValue::Null(get_empty_token()),
-            FBConstValueKind::String => {
-                ConstantValue::String(get_string_node(value.string_value()?.to_string()))
-            }
-            FBConstValueKind::Bool => ConstantValue::Boolean(get_boolean_node(value.bool_value())),
-            FBConstValueKind::Int => {
-                ConstantValue::Int(get_int_node(value.int_value()?.to_string()))
-            }
-            FBConstValueKind::Float => {
-                ConstantValue::Float(get_float_node(value.float_value()?.to_string()))
-            }
-            FBConstValueKind::Enum => {
-                ConstantValue::Enum(get_enum_node(value.enum_value()?.to_string()))
-            }
-            FBConstValueKind::List => {
-                ConstantValue::List(self.parse_list_value(value.list_value()?)?)
-            }
-            FBConstValueKind::Object => {
-                ConstantValue::Object(self.parse_object_value(value.object_value()?)?)
+    fn parse_const_value(
+        &self,
+        value: FBConstValue<'fb>,
+    ) -> Result<ConstantValue, SchemaReadError> {
+        let token = get_token(get_span(value.span()));
+        Ok(match value.kind() {
+            FBConstValueKind::Null => ConstantValue::Null(token),
+            FBConstValueKind::String => ConstantValue::String(get_string_node(
+                value
+                    .string_value()
+                    .ok_or_else(|| SchemaReadError::missing_field("const_value.string_value()"))?
+                    .to_string(),
+                token,
+            )),
+            FBConstValueKind::Bool => {
+                ConstantValue::Boolean(get_boolean_node(value.bool_value(), token))
             }
+            FBConstValueKind::Int => ConstantValue::Int(get_int_node(
+                value
+                    .int_value()
+                    .ok_or_else(|| SchemaReadError::missing_field("const_value.int_value()"))?
+                    .to_string(),
+                token,
+            )?),
+            FBConstValueKind::Float => ConstantValue::Float(get_float_node(
+                value
+                    .float_value()
+                    .ok_or_else(|| SchemaReadError::missing_field("const_value.float_value()"))?
+                    .to_string(),
+                token,
+            )?),
+            FBConstValueKind::Enum => ConstantValue::Enum(get_enum_node(
+                value
+                    .enum_value()
+                    .ok_or_else(|| SchemaReadError::missing_field("const_value.enum_value()"))?
+                    .to_string(),
+                token,
+            )),
+            FBConstValueKind::List => ConstantValue::List(self.parse_list_value(
+                value
+                    .list_value()
+                    .ok_or_else(|| SchemaReadError::missing_field("const_value.list_value()"))?,
+            )?),
+            FBConstValueKind::Object => ConstantValue::Object(self.parse_object_value(
+                value
+                    .object_value()
+                    .ok_or_else(|| SchemaReadError::missing_field("const_value.object_value()"))?,
+            )?),
         })
     }
 
-    fn parse_list_value(&self, list_value: FBListValue<'fb>) -> Option<List<ConstantValue>> {
+    fn parse_list_value(
+        &self,
+        list_value: FBListValue<'fb>,
+    ) -> Result<List<ConstantValue>, SchemaReadError> {
+        let span = get_span(list_value.span());
         let items = list_value
-            .values()?
+            .values()
+            .ok_or_else(|| SchemaReadError::missing_field("list_value.values()"))?
             .iter()
-            .map(|value| self.parse_const_value(value))
-            .collect::<Option<Vec<_>>>();
-        Some(List {
-            span: get_empty_span(),
-            start: get_empty_token(),
-            items: items?,
-            end: get_empty_token(),
+            .enumerate()
+            .map(|(index, value)| {
+                self.parse_const_value(value)
+                    .map_err(|err| err.with_index(index))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(List {
+            span,
+            start: get_token(span),
+            items,
+            end: get_token(span),
         })
     }
 
     fn parse_object_value(
         &self,
         object_value: FBObjectValue<'fb>,
-    ) -> Option<List<ConstantArgument>> {
+    ) -> Result<List<ConstantArgument>, SchemaReadError> {
+        let span = get_span(object_value.span());
         let items = object_value
-            .fields()?
+            .fields()
+            .ok_or_else(|| SchemaReadError::missing_field("object_value.fields()"))?
             .iter()
-            .map(|field| {
-                Some(ConstantArgument {
-                    span: get_empty_span(),
-                    name: get_identifier(field.name()?.to_string()),
-                    colon: get_empty_token(),
-                    value: self.parse_const_value(field.value()?)?,
-                })
+            .enumerate()
+            .map(|(index, field)| {
+                let parse = || -> Result<ConstantArgument, SchemaReadError> {
+                    let field_span = get_span(field.span());
+                    Ok(ConstantArgument {
+                        span: field_span,
+                        name: get_identifier(
+                            field
+                                .name()
+                                .ok_or_else(|| SchemaReadError::missing_field("object_field.name()"))?
+                                .to_string(),
+                            field_span,
+                        ),
+                        colon: get_token(field_span),
+                        value: self.parse_const_value(field.value().ok_or_else(|| {
+                            SchemaReadError::missing_field("object_field.value()")
+                        })?)?,
+                    })
+                };
+                parse().map_err(|err| err.with_index(index))
             })
-            .collect::<Option<Vec<_>>>();
-        Some(List {
-            span: get_empty_span(),
-            start: get_empty_token(),
-            items: items?,
-            end: get_empty_token(),
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(List {
+            span,
+            start: get_token(span),
+            items,
+            end: get_token(span),
         })
     }
 
-    fn get_fbtype_name(&self, type_: &FBType<'_>) -> StringKey {
-        match type_.kind() {
+    fn get_fbtype_name(&self, type_: &FBType<'_>) -> Result<StringKey, SchemaReadError> {
+        let name = match type_.kind() {
             FBTypeKind::Scalar => self
                 .fb_schema
                 .scalars()
-                .unwrap()
-                .get(type_.scalar_id().try_into().unwrap())
+                .ok_or_else(|| SchemaReadError::missing_field("schema.scalars()"))?
+                .get(
+                    type_
+                        .scalar_id()
+                        .try_into()
+                        .map_err(|_| SchemaReadError::id_out_of_range("scalar_id", type_.scalar_id()))?,
+                )
                 .name(),
             FBTypeKind::InputObject => self
                 .fb_schema
                 .input_objects()
-                .unwrap()
-                .get(type_.input_object_id().try_into().unwrap())
+                .ok_or_else(|| SchemaReadError::missing_field("schema.input_objects()"))?
+                .get(type_.input_object_id().try_into().map_err(|_| {
+                    SchemaReadError::id_out_of_range("input_object_id", type_.input_object_id())
+                })?)
                 .name(),
             FBTypeKind::Enum => self
                 .fb_schema
                 .enums()
-                .unwrap()
-                .get(type_.enum_id().try_into().unwrap())
+                .ok_or_else(|| SchemaReadError::missing_field("schema.enums()"))?
+                .get(
+                    type_
+                        .enum_id()
+                        .try_into()
+                        .map_err(|_| SchemaReadError::id_out_of_range("enum_id", type_.enum_id()))?,
+                )
                 .name(),
             FBTypeKind::Object => self
                 .fb_schema
                 .objects()
-                .unwrap()
-                .get(type_.object_id().try_into().unwrap())
+                .ok_or_else(|| SchemaReadError::missing_field("schema.objects()"))?
+                .get(
+                    type_
+                        .object_id()
+                        .try_into()
+                        .map_err(|_| SchemaReadError::id_out_of_range("object_id", type_.object_id()))?,
+                )
                 .name(),
             FBTypeKind::Interface => self
                 .fb_schema
                 .interfaces()
-                .unwrap()
-                .get(type_.interface_id().try_into().unwrap())
+                .ok_or_else(|| SchemaReadError::missing_field("schema.interfaces()"))?
+                .get(type_.interface_id().try_into().map_err(|_| {
+                    SchemaReadError::id_out_of_range("interface_id", type_.interface_id())
+                })?)
                 .name(),
             FBTypeKind::Union => self
                 .fb_schema
                 .unions()
-                .unwrap()
-                .get(type_.union_id().try_into().unwrap())
+                .ok_or_else(|| SchemaReadError::missing_field("schema.unions()"))?
+                .get(
+                    type_
+                        .union_id()
+                        .try_into()
+                        .map_err(|_| SchemaReadError::id_out_of_range("union_id", type_.union_id()))?,
+                )
                 .name(),
         }
-        .unwrap()
-        .intern()
+        .ok_or_else(|| SchemaReadError::missing_field("type.name()"))?;
+        Ok(name.intern())
+    }
+}
+
+/// Checks a const value against one of the built-in scalars by name.
+/// Custom scalars have no declared representation to check against, so any
+/// non-list, non-object literal is accepted for them.
+fn validate_scalar_const_value(value: &ConstantValue, scalar_name: StringKey) -> Result<(), SchemaReadError> {
+    let mismatch = |expected: &str| {
+        Err(SchemaReadError::new(format!(
+            "expected a {} default value for scalar `{}`",
+            expected, scalar_name
+        )))
+    };
+    match scalar_name.lookup() {
+        "Int" => match value {
+            ConstantValue::Int(_) => Ok(()),
+            _ => mismatch("int"),
+        },
+        "Float" => match value {
+            ConstantValue::Float(_) | ConstantValue::Int(_) => Ok(()),
+            _ => mismatch("float"),
+        },
+        "String" => match value {
+            ConstantValue::String(_) => Ok(()),
+            _ => mismatch("string"),
+        },
+        "ID" => match value {
+            ConstantValue::String(_) | ConstantValue::Int(_) => Ok(()),
+            _ => mismatch("string or int"),
+        },
+        "Boolean" => match value {
+            ConstantValue::Boolean(_) => Ok(()),
+            _ => mismatch("boolean"),
+        },
+        _ => match value {
+            ConstantValue::List(_) | ConstantValue::Object(_) => mismatch("scalar"),
+            _ => Ok(()),
+        },
     }
 }
 
-fn get_identifier(value: String) -> Identifier {
+fn get_identifier(value: String, span: Span) -> Identifier {
     Identifier {
-        span: get_empty_span(),
-        token: get_empty_token(),
+        span,
+        token: get_token(span),
         value: value.intern(),
     }
 }
 
-fn get_enum_node(value: String) -> EnumNode {
+fn get_enum_node(value: String, token: Token) -> EnumNode {
     EnumNode {
-        token: get_empty_token(),
+        token,
         value: value.intern(),
     }
 }
 
-fn get_float_node(value: String) -> FloatNode {
-    FloatNode {
-        token: get_empty_token(),
-        value: FloatValue::new(value.parse::<f64>().unwrap()),
+fn get_float_node(value: String, token: Token) -> Result<FloatNode, SchemaReadError> {
+    let parsed = value
+        .parse::<f64>()
+        .map_err(|err| SchemaReadError::new(format!("invalid float default `{}`: {}", value, err)))?;
+    Ok(FloatNode {
+        token,
+        value: FloatValue::new(parsed),
         source_value: value.intern(),
-    }
+    })
 }
 
-fn get_int_node(value: String) -> IntNode {
-    IntNode {
-        token: get_empty_token(),
-        value: value.parse().unwrap(),
-    }
+fn get_int_node(value: String, token: Token) -> Result<IntNode, SchemaReadError> {
+    let parsed = value
+        .parse()
+        .map_err(|err| SchemaReadError::new(format!("invalid int default `{}`: {}", value, err)))?;
+    Ok(IntNode {
+        token,
+        value: parsed,
+    })
 }
 
-fn get_boolean_node(value: bool) -> BooleanNode {
-    BooleanNode {
-        token: get_empty_token(),
-        value,
-    }
+fn get_boolean_node(value: bool, token: Token) -> BooleanNode {
+    BooleanNode { token, value }
 }
 
-fn get_string_node(value: String) -> StringNode {
+fn get_string_node(value: String, token: Token) -> StringNode {
     StringNode {
-        token: get_empty_token(),
+        token,
         value: value.intern(),
     }
 }
 
-fn get_empty_token() -> Token {
+fn get_token(span: Span) -> Token {
     Token {
-        span: get_empty_span(),
+        span,
         kind: TokenKind::EndOfFile,
     }
 }
@@ -523,6 +1111,18 @@ fn get_empty_span() -> Span {
     Span { start: 0, end: 0 }
 }
 
+/// Falls back to the empty span for buffers serialized before spans were
+/// added to the FlatBuffer schema format.
+fn get_span(fb_span: Option<FBSpan<'_>>) -> Span {
+    match fb_span {
+        Some(fb_span) => Span {
+            start: fb_span.start(),
+            end: fb_span.end(),
+        },
+        None => get_empty_span(),
+    }
+}
+
 fn get_mapped_location(location: FBDirectiveLocation) -> DirectiveLocation {
     match location {
         FBDirectiveLocation::Query => DirectiveLocation::Query,
@@ -545,4 +1145,4 @@ fn get_mapped_location(location: FBDirectiveLocation) -> DirectiveLocation {
         FBDirectiveLocation::InputFieldDefinition => DirectiveLocation::InputFieldDefinition,
         FBDirectiveLocation::VariableDefinition => DirectiveLocation::VariableDefinition,
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,717 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use crate::definitions::{
+    Directive, DirectiveValue, Enum, EnumValue, Field, InputObject, Interface, Object, Scalar,
+    Schema, Type, TypeReference, Union,
+};
+use crate::graphqlschema_generated::graphqlschema::*;
+use common::Span;
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+use graphql_syntax::{ConstantValue, DirectiveLocation};
+use interner::StringKey;
+
+/// Serializes an in-memory `Schema` into the same FlatBuffer format that
+/// `FlatBufferSchema` reads. The `types` and `directives` vectors are
+/// written sorted by the type/directive's string name (not its intern id),
+/// matching the byte-string ordering `read_type` and `read_directive` rely
+/// on for their binary search.
+pub fn serialize_schema(schema: &Schema) -> Vec<u8> {
+    let mut builder = FlatBufferBuilder::new();
+    let writer = SchemaWriter::new(schema, &mut builder);
+    let fb_schema = writer.write();
+    builder.finish(fb_schema, None);
+    builder.finished_data().to_vec()
+}
+
+struct SchemaWriter<'a, 'b> {
+    schema: &'a Schema,
+    builder: &'a mut FlatBufferBuilder<'b>,
+}
+
+impl<'a, 'b> SchemaWriter<'a, 'b> {
+    fn new(schema: &'a Schema, builder: &'a mut FlatBufferBuilder<'b>) -> Self {
+        Self { schema, builder }
+    }
+
+    fn write(mut self) -> WIPOffset<FBSchema<'b>> {
+        let scalars = self.write_vec(self.schema.get_scalars(), Self::write_scalar);
+        let input_objects = self.write_vec(self.schema.get_input_objects(), Self::write_input_object);
+        let enums = self.write_vec(self.schema.get_enums(), Self::write_enum);
+        let objects = self.write_vec(self.schema.get_objects(), Self::write_object);
+        let interfaces = self.write_vec(self.schema.get_interfaces(), Self::write_interface);
+        let unions = self.write_vec(self.schema.get_unions(), Self::write_union);
+        let fields = self.write_vec(self.schema.get_fields(), Self::write_field);
+
+        let mut type_entries: Vec<(StringKey, Type)> = self.schema.get_type_map().collect();
+        type_entries.sort_by_key(|(name, _)| name.lookup());
+        let types = type_entries
+            .iter()
+            .map(|(name, type_)| self.write_type_map_entry(*name, *type_))
+            .collect::<Vec<_>>();
+        let types = self.builder.create_vector(&types);
+
+        let mut directive_entries: Vec<(StringKey, &Directive)> =
+            self.schema.get_directive_map().collect();
+        directive_entries.sort_by_key(|(name, _)| name.lookup());
+        let directives = directive_entries
+            .iter()
+            .map(|(name, directive)| self.write_directive_map_entry(*name, directive))
+            .collect::<Vec<_>>();
+        let directives = self.builder.create_vector(&directives);
+
+        FBSchema::create(
+            self.builder,
+            &FBSchemaArgs {
+                scalars: Some(scalars),
+                input_objects: Some(input_objects),
+                enums: Some(enums),
+                objects: Some(objects),
+                interfaces: Some(interfaces),
+                unions: Some(unions),
+                fields: Some(fields),
+                types: Some(types),
+                directives: Some(directives),
+            },
+        )
+    }
+
+    fn write_vec<T, F, O>(&mut self, items: impl Iterator<Item = T>, write_one: F) -> WIPOffset<flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<O>>>
+    where
+        F: Fn(&mut Self, T) -> WIPOffset<O>,
+        O: 'b,
+    {
+        let offsets = items.map(|item| write_one(self, item)).collect::<Vec<_>>();
+        self.builder.create_vector(&offsets)
+    }
+
+    fn write_type_map_entry(&mut self, name: StringKey, type_: Type) -> WIPOffset<FBTypeMap<'b>> {
+        let key = self.builder.create_string(name.lookup());
+        let value = self.write_type_reference_entry(type_);
+        FBTypeMap::create(
+            self.builder,
+            &FBTypeMapArgs {
+                key: Some(key),
+                value: Some(value),
+            },
+        )
+    }
+
+    fn write_type_reference_entry(&mut self, type_: Type) -> WIPOffset<FBType<'b>> {
+        let (kind, scalar_id, input_object_id, enum_id, object_id, interface_id, union_id) =
+            match type_ {
+                Type::Scalar(id) => (FBTypeKind::Scalar, id.as_usize() as u32, 0, 0, 0, 0, 0),
+                Type::InputObject(id) => {
+                    (FBTypeKind::InputObject, 0, id.as_usize() as u32, 0, 0, 0, 0)
+                }
+                Type::Enum(id) => (FBTypeKind::Enum, 0, 0, id.as_usize() as u32, 0, 0, 0),
+                Type::Object(id) => (FBTypeKind::Object, 0, 0, 0, id.as_usize() as u32, 0, 0),
+                Type::Interface(id) => {
+                    (FBTypeKind::Interface, 0, 0, 0, 0, id.as_usize() as u32, 0)
+                }
+                Type::Union(id) => (FBTypeKind::Union, 0, 0, 0, 0, 0, id.as_usize() as u32),
+            };
+        FBType::create(
+            self.builder,
+            &FBTypeArgs {
+                kind,
+                scalar_id,
+                input_object_id,
+                enum_id,
+                object_id,
+                interface_id,
+                union_id,
+            },
+        )
+    }
+
+    fn write_directive_map_entry(
+        &mut self,
+        name: StringKey,
+        directive: &Directive,
+    ) -> WIPOffset<FBDirectiveMap<'b>> {
+        let key = self.builder.create_string(name.lookup());
+        let value = self.write_directive(directive);
+        FBDirectiveMap::create(
+            self.builder,
+            &FBDirectiveMapArgs {
+                key: Some(key),
+                value: Some(value),
+            },
+        )
+    }
+
+    fn write_directive(&mut self, directive: &Directive) -> WIPOffset<FBDirective<'b>> {
+        let name = self.builder.create_string(directive.name.lookup());
+        let arguments = self.write_vec(directive.arguments.iter(), Self::write_argument);
+        let locations = directive
+            .locations
+            .iter()
+            .map(|location| get_fb_location(*location))
+            .collect::<Vec<_>>();
+        let locations = self.builder.create_vector(&locations);
+        FBDirective::create(
+            self.builder,
+            &FBDirectiveArgs {
+                name: Some(name),
+                is_extension: directive.is_extension,
+                repeatable: directive.repeatable,
+                arguments: Some(arguments),
+                locations: Some(locations),
+            },
+        )
+    }
+
+    fn write_scalar(&mut self, scalar: &Scalar) -> WIPOffset<FBScalar<'b>> {
+        let name = self.builder.create_string(scalar.name.lookup());
+        let directives = self.write_vec(scalar.directives.iter(), Self::write_directive_value);
+        FBScalar::create(
+            self.builder,
+            &FBScalarArgs {
+                name: Some(name),
+                is_extension: scalar.is_extension,
+                directives: Some(directives),
+            },
+        )
+    }
+
+    fn write_input_object(&mut self, input_object: &InputObject) -> WIPOffset<FBInputObject<'b>> {
+        let name = self.builder.create_string(input_object.name.lookup());
+        let fields = self.write_vec(input_object.fields.iter(), Self::write_argument);
+        let directives =
+            self.write_vec(input_object.directives.iter(), Self::write_directive_value);
+        FBInputObject::create(
+            self.builder,
+            &FBInputObjectArgs {
+                name: Some(name),
+                fields: Some(fields),
+                directives: Some(directives),
+            },
+        )
+    }
+
+    fn write_enum(&mut self, enum_: &Enum) -> WIPOffset<FBEnum<'b>> {
+        let name = self.builder.create_string(enum_.name.lookup());
+        let values = self.write_vec(enum_.values.iter(), Self::write_enum_value);
+        let directives = self.write_vec(enum_.directives.iter(), Self::write_directive_value);
+        FBEnum::create(
+            self.builder,
+            &FBEnumArgs {
+                name: Some(name),
+                is_extension: enum_.is_extension,
+                values: Some(values),
+                directives: Some(directives),
+            },
+        )
+    }
+
+    fn write_enum_value(&mut self, value: &EnumValue) -> WIPOffset<FBEnumValue<'b>> {
+        let fb_value = self.builder.create_string(value.value.lookup());
+        let directives = self.write_vec(value.directives.iter(), Self::write_directive_value);
+        FBEnumValue::create(
+            self.builder,
+            &FBEnumValueArgs {
+                value: Some(fb_value),
+                directives: Some(directives),
+            },
+        )
+    }
+
+    fn write_object(&mut self, object: &Object) -> WIPOffset<FBObject<'b>> {
+        let name = self.builder.create_string(object.name.lookup());
+        let field_ids = object
+            .fields
+            .iter()
+            .map(|field_id| field_id.as_usize() as u32)
+            .collect::<Vec<_>>();
+        let fields = self.builder.create_vector(&field_ids);
+        let interface_ids = object
+            .interfaces
+            .iter()
+            .map(|interface_id| interface_id.as_usize() as u32)
+            .collect::<Vec<_>>();
+        let interfaces = self.builder.create_vector(&interface_ids);
+        let directives = self.write_vec(object.directives.iter(), Self::write_directive_value);
+        FBObject::create(
+            self.builder,
+            &FBObjectArgs {
+                name: Some(name),
+                is_extension: object.is_extension,
+                fields: Some(fields),
+                interfaces: Some(interfaces),
+                directives: Some(directives),
+            },
+        )
+    }
+
+    fn write_interface(&mut self, interface: &Interface) -> WIPOffset<FBInterface<'b>> {
+        let name = self.builder.create_string(interface.name.lookup());
+        let field_ids = interface
+            .fields
+            .iter()
+            .map(|field_id| field_id.as_usize() as u32)
+            .collect::<Vec<_>>();
+        let fields = self.builder.create_vector(&field_ids);
+        let interface_ids = interface
+            .interfaces
+            .iter()
+            .map(|interface_id| interface_id.as_usize() as u32)
+            .collect::<Vec<_>>();
+        let interfaces = self.builder.create_vector(&interface_ids);
+        let implementing_object_ids = interface
+            .implementing_objects
+            .iter()
+            .map(|object_id| object_id.as_usize() as u32)
+            .collect::<Vec<_>>();
+        let implementing_objects = self.builder.create_vector(&implementing_object_ids);
+        let directives = self.write_vec(interface.directives.iter(), Self::write_directive_value);
+        FBInterface::create(
+            self.builder,
+            &FBInterfaceArgs {
+                name: Some(name),
+                is_extension: interface.is_extension,
+                fields: Some(fields),
+                interfaces: Some(interfaces),
+                implementing_objects: Some(implementing_objects),
+                directives: Some(directives),
+            },
+        )
+    }
+
+    fn write_union(&mut self, union: &Union) -> WIPOffset<FBUnion<'b>> {
+        let name = self.builder.create_string(union.name.lookup());
+        let member_ids = union
+            .members
+            .iter()
+            .map(|object_id| object_id.as_usize() as u32)
+            .collect::<Vec<_>>();
+        let members = self.builder.create_vector(&member_ids);
+        let directives = self.write_vec(union.directives.iter(), Self::write_directive_value);
+        FBUnion::create(
+            self.builder,
+            &FBUnionArgs {
+                name: Some(name),
+                is_extension: union.is_extension,
+                members: Some(members),
+                directives: Some(directives),
+            },
+        )
+    }
+
+    fn write_field(&mut self, field: &Field) -> WIPOffset<FBField<'b>> {
+        let name = self.builder.create_string(field.name.lookup());
+        let arguments = self.write_vec(field.arguments.iter(), Self::write_argument);
+        let type_ = self.write_type_reference(&field.type_);
+        let directives = self.write_vec(field.directives.iter(), Self::write_directive_value);
+        let parent_type = field
+            .parent_type
+            .map(|parent_type| self.write_type_reference_entry(parent_type));
+        FBField::create(
+            self.builder,
+            &FBFieldArgs {
+                name: Some(name),
+                is_extension: field.is_extension,
+                arguments: Some(arguments),
+                type_: Some(type_),
+                directives: Some(directives),
+                parent_type,
+            },
+        )
+    }
+
+    fn write_argument(&mut self, argument: &crate::definitions::Argument) -> WIPOffset<FBArgument<'b>> {
+        let name = self.builder.create_string(argument.name.lookup());
+        let type_ = self.write_type_reference(&argument.type_);
+        let value = argument
+            .default_value
+            .as_ref()
+            .map(|value| self.write_const_value(value));
+        FBArgument::create(
+            self.builder,
+            &FBArgumentArgs {
+                name: Some(name),
+                type_: Some(type_),
+                value,
+            },
+        )
+    }
+
+    fn write_type_reference(&mut self, type_reference: &TypeReference) -> WIPOffset<FBTypeReference<'b>> {
+        match type_reference {
+            TypeReference::Named(type_) => {
+                let named = self.write_type_reference_entry(*type_);
+                FBTypeReference::create(
+                    self.builder,
+                    &FBTypeReferenceArgs {
+                        kind: FBTypeReferenceKind::Named,
+                        named: Some(named),
+                        null: None,
+                        list: None,
+                    },
+                )
+            }
+            TypeReference::NonNull(inner) => {
+                let null = self.write_type_reference(inner);
+                FBTypeReference::create(
+                    self.builder,
+                    &FBTypeReferenceArgs {
+                        kind: FBTypeReferenceKind::NonNull,
+                        named: None,
+                        null: Some(null),
+                        list: None,
+                    },
+                )
+            }
+            TypeReference::List(inner) => {
+                let list = self.write_type_reference(inner);
+                FBTypeReference::create(
+                    self.builder,
+                    &FBTypeReferenceArgs {
+                        kind: FBTypeReferenceKind::List,
+                        named: None,
+                        null: None,
+                        list: Some(list),
+                    },
+                )
+            }
+        }
+    }
+
+    fn write_directive_value(&mut self, directive: &DirectiveValue) -> WIPOffset<FBDirectiveValue<'b>> {
+        let name = self.builder.create_string(directive.name.lookup());
+        let arguments = self.write_vec(directive.arguments.iter(), Self::write_argument_value);
+        FBDirectiveValue::create(
+            self.builder,
+            &FBDirectiveValueArgs {
+                name: Some(name),
+                arguments: Some(arguments),
+            },
+        )
+    }
+
+    fn write_argument_value(
+        &mut self,
+        argument: &graphql_syntax::ArgumentValue,
+    ) -> WIPOffset<FBArgumentValue<'b>> {
+        let name = self.builder.create_string(argument.name.lookup());
+        let value = self.write_const_value(&argument.value);
+        FBArgumentValue::create(
+            self.builder,
+            &FBArgumentValueArgs {
+                name: Some(name),
+                value: Some(value),
+            },
+        )
+    }
+
+    fn write_span(&mut self, span: &Span) -> WIPOffset<FBSpan<'b>> {
+        FBSpan::create(
+            self.builder,
+            &FBSpanArgs {
+                start: span.start,
+                end: span.end,
+            },
+        )
+    }
+
+    fn write_const_value(&mut self, value: &ConstantValue) -> WIPOffset<FBConstValue<'b>> {
+        let span_value = self.span_of(value);
+        let span = self.write_span(&span_value);
+        match value {
+            ConstantValue::Null(_) => FBConstValue::create(
+                self.builder,
+                &FBConstValueArgs {
+                    kind: FBConstValueKind::Null,
+                    span: Some(span),
+                    ..Default::default()
+                },
+            ),
+            ConstantValue::String(node) => {
+                let string_value = self.builder.create_string(node.value.lookup());
+                FBConstValue::create(
+                    self.builder,
+                    &FBConstValueArgs {
+                        kind: FBConstValueKind::String,
+                        span: Some(span),
+                        string_value: Some(string_value),
+                        ..Default::default()
+                    },
+                )
+            }
+            ConstantValue::Boolean(node) => FBConstValue::create(
+                self.builder,
+                &FBConstValueArgs {
+                    kind: FBConstValueKind::Bool,
+                    span: Some(span),
+                    bool_value: node.value,
+                    ..Default::default()
+                },
+            ),
+            ConstantValue::Int(node) => {
+                let int_value = self.builder.create_string(&node.value.to_string());
+                FBConstValue::create(
+                    self.builder,
+                    &FBConstValueArgs {
+                        kind: FBConstValueKind::Int,
+                        span: Some(span),
+                        int_value: Some(int_value),
+                        ..Default::default()
+                    },
+                )
+            }
+            ConstantValue::Float(node) => {
+                let float_value = self.builder.create_string(node.source_value.lookup());
+                FBConstValue::create(
+                    self.builder,
+                    &FBConstValueArgs {
+                        kind: FBConstValueKind::Float,
+                        span: Some(span),
+                        float_value: Some(float_value),
+                        ..Default::default()
+                    },
+                )
+            }
+            ConstantValue::Enum(node) => {
+                let enum_value = self.builder.create_string(node.value.lookup());
+                FBConstValue::create(
+                    self.builder,
+                    &FBConstValueArgs {
+                        kind: FBConstValueKind::Enum,
+                        span: Some(span),
+                        enum_value: Some(enum_value),
+                        ..Default::default()
+                    },
+                )
+            }
+            ConstantValue::List(list) => {
+                let items = self.write_vec(list.items.iter(), Self::write_const_value);
+                let list_value = FBListValue::create(
+                    self.builder,
+                    &FBListValueArgs {
+                        span: Some(span),
+                        values: Some(items),
+                    },
+                );
+                FBConstValue::create(
+                    self.builder,
+                    &FBConstValueArgs {
+                        kind: FBConstValueKind::List,
+                        span: Some(span),
+                        list_value: Some(list_value),
+                        ..Default::default()
+                    },
+                )
+            }
+            ConstantValue::Object(list) => {
+                let fields = self.write_vec(list.items.iter(), Self::write_object_field);
+                let object_value = FBObjectValue::create(
+                    self.builder,
+                    &FBObjectValueArgs {
+                        span: Some(span),
+                        fields: Some(fields),
+                    },
+                );
+                FBConstValue::create(
+                    self.builder,
+                    &FBConstValueArgs {
+                        kind: FBConstValueKind::Object,
+                        span: Some(span),
+                        object_value: Some(object_value),
+                        ..Default::default()
+                    },
+                )
+            }
+        }
+    }
+
+    fn write_object_field(
+        &mut self,
+        field: &graphql_syntax::ConstantArgument,
+    ) -> WIPOffset<FBObjectValueField<'b>> {
+        let name = self.builder.create_string(field.name.value.lookup());
+        let value = self.write_const_value(&field.value);
+        let span = self.write_span(&field.span);
+        FBObjectValueField::create(
+            self.builder,
+            &FBObjectValueFieldArgs {
+                name: Some(name),
+                span: Some(span),
+                value: Some(value),
+            },
+        )
+    }
+
+    fn span_of(&self, value: &ConstantValue) -> Span {
+        match value {
+            ConstantValue::Null(token) => token.span,
+            ConstantValue::String(node) => node.token.span,
+            ConstantValue::Boolean(node) => node.token.span,
+            ConstantValue::Int(node) => node.token.span,
+            ConstantValue::Float(node) => node.token.span,
+            ConstantValue::Enum(node) => node.token.span,
+            ConstantValue::List(list) => list.span,
+            ConstantValue::Object(list) => list.span,
+        }
+    }
+}
+
+fn get_fb_location(location: DirectiveLocation) -> FBDirectiveLocation {
+    use DirectiveLocation as L;
+    match location {
+        L::Query => FBDirectiveLocation::Query,
+        L::Mutation => FBDirectiveLocation::Mutation,
+        L::Subscription => FBDirectiveLocation::Subscription,
+        L::Field => FBDirectiveLocation::Field,
+        L::FragmentDefinition => FBDirectiveLocation::FragmentDefinition,
+        L::FragmentSpread => FBDirectiveLocation::FragmentSpread,
+        L::InlineFragment => FBDirectiveLocation::InlineFragment,
+        L::Schema => FBDirectiveLocation::Schema,
+        L::Scalar => FBDirectiveLocation::Scalar,
+        L::Object => FBDirectiveLocation::Object,
+        L::FieldDefinition => FBDirectiveLocation::FieldDefinition,
+        L::ArgumentDefinition => FBDirectiveLocation::ArgumentDefinition,
+        L::Interface => FBDirectiveLocation::Interface,
+        L::Union => FBDirectiveLocation::Union,
+        L::Enum => FBDirectiveLocation::Enum,
+        L::EnumValue => FBDirectiveLocation::EnumValue,
+        L::InputObject => FBDirectiveLocation::InputObject,
+        L::InputFieldDefinition => FBDirectiveLocation::InputFieldDefinition,
+        L::VariableDefinition => FBDirectiveLocation::VariableDefinition,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::definitions::Argument;
+    use crate::definitions::ArgumentDefinitions;
+    use crate::fb_schema::FlatBufferSchema;
+    use graphql_syntax::IntNode;
+    use graphql_syntax::Token;
+    use graphql_syntax::TokenKind;
+    use interner::Intern;
+
+    fn synthetic_token() -> Token {
+        Token {
+            span: Span { start: 0, end: 0 },
+            kind: TokenKind::EndOfFile,
+        }
+    }
+
+    /// Writes a schema with enough types/directives that name order and
+    /// intern-id order diverge, then reads it back through
+    /// `FlatBufferSchema`. This is the round trip `read_type`/`read_directive`
+    /// rely on finding every entry through their binary search -- vectors
+    /// sorted by intern id instead of by name would leave entries whose id
+    /// order doesn't match their string order unreachable by that search.
+    #[test]
+    fn round_trips_types_directives_and_const_defaults() {
+        let mut schema = Schema::default();
+
+        let int_id = schema
+            .add_scalar(Scalar {
+                name: "Int".intern(),
+                is_extension: false,
+                directives: vec![],
+            })
+            .unwrap();
+        schema
+            .add_scalar(Scalar {
+                name: "ZzRoundTripScalar".intern(),
+                is_extension: false,
+                directives: vec![],
+            })
+            .unwrap();
+        schema
+            .add_scalar(Scalar {
+                name: "AaRoundTripScalar".intern(),
+                is_extension: false,
+                directives: vec![],
+            })
+            .unwrap();
+
+        schema
+            .add_directive(Directive {
+                name: "zzRoundTripDirective".intern(),
+                is_extension: false,
+                arguments: ArgumentDefinitions::new(vec![]),
+                locations: vec![DirectiveLocation::FieldDefinition],
+                repeatable: false,
+            })
+            .unwrap();
+        schema
+            .add_directive(Directive {
+                name: "aaRoundTripDirective".intern(),
+                is_extension: false,
+                arguments: ArgumentDefinitions::new(vec![]),
+                locations: vec![DirectiveLocation::FieldDefinition],
+                repeatable: false,
+            })
+            .unwrap();
+
+        let object_id = schema
+            .add_object(Object {
+                name: "Query".intern(),
+                is_extension: false,
+                fields: vec![],
+                interfaces: vec![],
+                directives: vec![],
+            })
+            .unwrap();
+        let argument = Argument {
+            name: "factor".intern(),
+            type_: TypeReference::Named(Type::Scalar(int_id)),
+            default_value: Some(ConstantValue::Int(IntNode {
+                token: synthetic_token(),
+                value: 5,
+            })),
+        };
+        let field_id = schema
+            .add_field(Field {
+                name: "value".intern(),
+                is_extension: false,
+                arguments: ArgumentDefinitions::new(vec![argument]),
+                type_: TypeReference::Named(Type::Scalar(int_id)),
+                directives: vec![],
+                parent_type: schema.get_type("Query".intern()),
+            })
+            .unwrap();
+        schema.add_field_to_object(object_id, field_id).unwrap();
+
+        let bytes = serialize_schema(&schema);
+        let mut round_tripped = FlatBufferSchema::build(&bytes, Schema::default()).unwrap();
+
+        for name in ["Int", "ZzRoundTripScalar", "AaRoundTripScalar"] {
+            assert!(
+                matches!(
+                    round_tripped.get_type(name.intern()),
+                    Ok(Some(Type::Scalar(_)))
+                ),
+                "expected scalar `{}` to round-trip",
+                name
+            );
+        }
+        for name in ["zzRoundTripDirective", "aaRoundTripDirective"] {
+            assert!(
+                round_tripped
+                    .get_directive(name.intern())
+                    .unwrap()
+                    .is_some(),
+                "expected directive `{}` to round-trip",
+                name
+            );
+        }
+        assert!(matches!(
+            round_tripped.get_type("Query".intern()),
+            Ok(Some(Type::Object(_)))
+        ));
+
+        let snapshot = round_tripped.snapshot_print();
+        assert!(snapshot.contains("factor"));
+        assert!(snapshot.contains('5'));
+    }
+}
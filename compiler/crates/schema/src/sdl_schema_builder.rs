@@ -0,0 +1,470 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use crate::definitions::{
+    Argument, ArgumentDefinitions, Directive, DirectiveValue, Enum, EnumValue, Field,
+    InputObject, Interface, Object, Scalar, Schema, SchemaError, Type, TypeReference, Union,
+};
+use graphql_syntax::{
+    ConstantDirective, ConstantValue, DirectiveDefinition, EnumTypeDefinition,
+    EnumTypeExtension, FieldDefinition, InputObjectTypeDefinition, InputObjectTypeExtension,
+    InputValueDefinition, InterfaceTypeDefinition, InterfaceTypeExtension, ObjectTypeDefinition,
+    ObjectTypeExtension, ScalarTypeDefinition, ScalarTypeExtension, TypeAnnotation,
+    TypeSystemDefinition, TypeSystemDocument, UnionTypeDefinition, UnionTypeExtension,
+};
+use interner::{Intern, StringKey};
+
+/// Builds the same in-memory `Schema` that `FlatBufferSchema` decodes from
+/// a buffer, but from a parsed SDL `TypeSystemDocument` instead. This lets
+/// callers load a schema straight from `.graphql` text without a FlatBuffer
+/// build step.
+///
+/// `extend` definitions are merged into an already-registered type. Added
+/// fields get `is_extension` set, and so does the extended type itself
+/// (except input objects, which have no such flag); added interfaces, union
+/// members and enum values carry no flag of their own. Forward references --
+/// a union member or implementing object defined later in the document --
+/// are resolved in a final pass after every definition has been read, the
+/// same way `parse_interface` resolves `implementing_objects` by name when
+/// reading a FlatBuffer.
+pub fn build_schema(document: &TypeSystemDocument) -> Result<Schema, SchemaError> {
+    let mut builder = SdlSchemaBuilder::default();
+    builder.build(document)
+}
+
+#[derive(Default)]
+struct SdlSchemaBuilder {
+    schema: Schema,
+    pending_object_interfaces: Vec<(StringKey, Vec<StringKey>)>,
+    pending_interface_interfaces: Vec<(StringKey, Vec<StringKey>)>,
+    pending_union_members: Vec<(StringKey, Vec<StringKey>)>,
+}
+
+impl SdlSchemaBuilder {
+    fn build(&mut self, document: &TypeSystemDocument) -> Result<Schema, SchemaError> {
+        // First pass: register every type by name so that forward
+        // references anywhere in the document resolve, and so `extend`
+        // blocks always have something to merge into. Directive
+        // definitions are added in the second pass.
+        for definition in &document.definitions {
+            self.declare(definition)?;
+        }
+
+        // Second pass: fill in fields, arguments, and directive values --
+        // plus record the interface/union edges we couldn't resolve yet.
+        for definition in &document.definitions {
+            self.populate(definition)?;
+        }
+
+        // Third pass: resolve the forward references collected above. Each
+        // name must resolve to a type of the expected kind -- an undefined
+        // or wrong-kind reference is a schema error, not something to skip,
+        // matching `build_type_annotation` below and the FlatBuffer reader's
+        // `parse_object`/`parse_interface`/`parse_union`.
+        for (object_name, interface_names) in std::mem::take(&mut self.pending_object_interfaces) {
+            let object_id = self
+                .schema
+                .get_type(object_name)
+                .and_then(Type::get_object_id)
+                .ok_or(SchemaError::UndefinedType(object_name))?;
+            for interface_name in interface_names {
+                let interface_id = self
+                    .schema
+                    .get_type(interface_name)
+                    .and_then(Type::get_interface_id)
+                    .ok_or(SchemaError::UndefinedType(interface_name))?;
+                self.schema
+                    .add_interface_to_object(object_id, interface_id)?;
+            }
+        }
+        for (interface_name, parent_names) in std::mem::take(&mut self.pending_interface_interfaces) {
+            let interface_id = self
+                .schema
+                .get_type(interface_name)
+                .and_then(Type::get_interface_id)
+                .ok_or(SchemaError::UndefinedType(interface_name))?;
+            for parent_name in parent_names {
+                let parent_id = self
+                    .schema
+                    .get_type(parent_name)
+                    .and_then(Type::get_interface_id)
+                    .ok_or(SchemaError::UndefinedType(parent_name))?;
+                self.schema
+                    .add_parent_interface_to_interface(interface_id, parent_id)?;
+            }
+        }
+        for (union_name, member_names) in std::mem::take(&mut self.pending_union_members) {
+            let union_id = self
+                .schema
+                .get_type(union_name)
+                .and_then(Type::get_union_id)
+                .ok_or(SchemaError::UndefinedType(union_name))?;
+            for member_name in member_names {
+                let object_id = self
+                    .schema
+                    .get_type(member_name)
+                    .and_then(Type::get_object_id)
+                    .ok_or(SchemaError::UndefinedType(member_name))?;
+                self.schema.add_member_to_union(union_id, object_id)?;
+            }
+        }
+
+        Ok(std::mem::take(&mut self.schema))
+    }
+
+    /// Registers an empty placeholder for every type-system definition so
+    /// later lookups (including forward references and `extend` targets)
+    /// always find something.
+    fn declare(&mut self, definition: &TypeSystemDefinition) -> Result<(), SchemaError> {
+        match definition {
+            TypeSystemDefinition::ScalarTypeDefinition(node) => {
+                self.schema.add_scalar(Scalar {
+                    name: node.name.value,
+                    is_extension: false,
+                    directives: vec![],
+                })?;
+            }
+            TypeSystemDefinition::ObjectTypeDefinition(node) => {
+                self.schema.add_object(Object {
+                    name: node.name.value,
+                    is_extension: false,
+                    fields: vec![],
+                    interfaces: vec![],
+                    directives: vec![],
+                })?;
+            }
+            TypeSystemDefinition::InterfaceTypeDefinition(node) => {
+                self.schema.add_interface(Interface {
+                    name: node.name.value,
+                    is_extension: false,
+                    implementing_objects: vec![],
+                    fields: vec![],
+                    directives: vec![],
+                    interfaces: vec![],
+                })?;
+            }
+            TypeSystemDefinition::UnionTypeDefinition(node) => {
+                self.schema.add_union(Union {
+                    name: node.name.value,
+                    is_extension: false,
+                    members: vec![],
+                    directives: vec![],
+                })?;
+            }
+            TypeSystemDefinition::EnumTypeDefinition(node) => {
+                self.schema.add_enum(Enum {
+                    name: node.name.value,
+                    is_extension: false,
+                    values: vec![],
+                    directives: vec![],
+                })?;
+            }
+            TypeSystemDefinition::InputObjectTypeDefinition(node) => {
+                self.schema.add_input_object(InputObject {
+                    name: node.name.value,
+                    fields: ArgumentDefinitions::new(vec![]),
+                    directives: vec![],
+                })?;
+            }
+            // `extend` blocks and schema/directive definitions don't
+            // introduce a new named type, so there's nothing to declare
+            // up front -- `populate` merges directly into what the
+            // matching definition above already registered.
+            TypeSystemDefinition::ObjectTypeExtension(_)
+            | TypeSystemDefinition::InterfaceTypeExtension(_)
+            | TypeSystemDefinition::UnionTypeExtension(_)
+            | TypeSystemDefinition::EnumTypeExtension(_)
+            | TypeSystemDefinition::InputObjectTypeExtension(_)
+            | TypeSystemDefinition::ScalarTypeExtension(_)
+            | TypeSystemDefinition::SchemaDefinition(_)
+            | TypeSystemDefinition::SchemaExtension(_)
+            | TypeSystemDefinition::DirectiveDefinition(_) => {}
+        }
+        Ok(())
+    }
+
+    fn populate(&mut self, definition: &TypeSystemDefinition) -> Result<(), SchemaError> {
+        match definition {
+            TypeSystemDefinition::ScalarTypeDefinition(node) => {
+                self.populate_scalar(node, false)
+            }
+            TypeSystemDefinition::ScalarTypeExtension(node) => {
+                self.populate_scalar(&node.scalar(), true)
+            }
+            TypeSystemDefinition::ObjectTypeDefinition(node) => {
+                self.populate_object(node, false)
+            }
+            TypeSystemDefinition::ObjectTypeExtension(node) => {
+                self.populate_object(&node.object(), true)
+            }
+            TypeSystemDefinition::InterfaceTypeDefinition(node) => {
+                self.populate_interface(node, false)
+            }
+            TypeSystemDefinition::InterfaceTypeExtension(node) => {
+                self.populate_interface(&node.interface(), true)
+            }
+            TypeSystemDefinition::UnionTypeDefinition(node) => self.populate_union(node, false),
+            TypeSystemDefinition::UnionTypeExtension(node) => {
+                self.populate_union(&node.union(), true)
+            }
+            TypeSystemDefinition::EnumTypeDefinition(node) => self.populate_enum(node, false),
+            TypeSystemDefinition::EnumTypeExtension(node) => {
+                self.populate_enum(&node.enum_(), true)
+            }
+            TypeSystemDefinition::InputObjectTypeDefinition(node) => {
+                self.populate_input_object(node, false)
+            }
+            TypeSystemDefinition::InputObjectTypeExtension(node) => {
+                self.populate_input_object(&node.input_object(), true)
+            }
+            TypeSystemDefinition::DirectiveDefinition(node) => self.populate_directive(node),
+            TypeSystemDefinition::SchemaDefinition(_) | TypeSystemDefinition::SchemaExtension(_) => {
+                Ok(())
+            }
+        }
+    }
+
+    fn populate_scalar(
+        &mut self,
+        node: &ScalarTypeDefinition,
+        is_extension: bool,
+    ) -> Result<(), SchemaError> {
+        let id = self
+            .schema
+            .get_type(node.name.value)
+            .and_then(Type::get_scalar_id)
+            .ok_or_else(|| SchemaError::UndefinedType(node.name.value))?;
+        let directives = self.build_directive_values(&node.directives)?;
+        let scalar = self.schema.scalar_mut(id);
+        scalar.is_extension = scalar.is_extension || is_extension;
+        scalar.directives.extend(directives);
+        Ok(())
+    }
+
+    fn populate_object(
+        &mut self,
+        node: &ObjectTypeDefinition,
+        is_extension: bool,
+    ) -> Result<(), SchemaError> {
+        let object_id = self
+            .schema
+            .get_type(node.name.value)
+            .and_then(Type::get_object_id)
+            .ok_or_else(|| SchemaError::UndefinedType(node.name.value))?;
+        for field_definition in &node.fields {
+            let field_id = self.build_field(field_definition, is_extension, node.name.value)?;
+            self.schema.add_field_to_object(object_id, field_id)?;
+        }
+        let interface_names = node
+            .interfaces
+            .iter()
+            .map(|interface| interface.value)
+            .collect::<Vec<_>>();
+        self.pending_object_interfaces
+            .push((node.name.value, interface_names));
+        let directives = self.build_directive_values(&node.directives)?;
+        let object = self.schema.object_mut(object_id);
+        object.is_extension = object.is_extension || is_extension;
+        object.directives.extend(directives);
+        Ok(())
+    }
+
+    fn populate_interface(
+        &mut self,
+        node: &InterfaceTypeDefinition,
+        is_extension: bool,
+    ) -> Result<(), SchemaError> {
+        let interface_id = self
+            .schema
+            .get_type(node.name.value)
+            .and_then(Type::get_interface_id)
+            .ok_or_else(|| SchemaError::UndefinedType(node.name.value))?;
+        for field_definition in &node.fields {
+            let field_id = self.build_field(field_definition, is_extension, node.name.value)?;
+            self.schema.add_field_to_interface(interface_id, field_id)?;
+        }
+        let interface_names = node
+            .interfaces
+            .iter()
+            .map(|interface| interface.value)
+            .collect::<Vec<_>>();
+        self.pending_interface_interfaces
+            .push((node.name.value, interface_names));
+        let directives = self.build_directive_values(&node.directives)?;
+        let interface = self.schema.interface_mut(interface_id);
+        interface.is_extension = interface.is_extension || is_extension;
+        interface.directives.extend(directives);
+        Ok(())
+    }
+
+    fn populate_union(
+        &mut self,
+        node: &UnionTypeDefinition,
+        is_extension: bool,
+    ) -> Result<(), SchemaError> {
+        let member_names = node.members.iter().map(|member| member.value).collect();
+        self.pending_union_members
+            .push((node.name.value, member_names));
+        let directives = self.build_directive_values(&node.directives)?;
+        let union_id = self
+            .schema
+            .get_type(node.name.value)
+            .and_then(Type::get_union_id)
+            .ok_or_else(|| SchemaError::UndefinedType(node.name.value))?;
+        let union_ = self.schema.union_mut(union_id);
+        union_.is_extension = union_.is_extension || is_extension;
+        union_.directives.extend(directives);
+        Ok(())
+    }
+
+    fn populate_enum(
+        &mut self,
+        node: &EnumTypeDefinition,
+        is_extension: bool,
+    ) -> Result<(), SchemaError> {
+        let enum_id = self
+            .schema
+            .get_type(node.name.value)
+            .and_then(Type::get_enum_id)
+            .ok_or_else(|| SchemaError::UndefinedType(node.name.value))?;
+        let directives = self.build_directive_values(&node.directives)?;
+        let values = node
+            .values
+            .iter()
+            .map(|value| {
+                Ok(EnumValue {
+                    value: value.value.value,
+                    directives: self.build_directive_values(&value.directives)?,
+                })
+            })
+            .collect::<Result<Vec<_>, SchemaError>>()?;
+        let enum_ = self.schema.enum_mut(enum_id);
+        enum_.is_extension = enum_.is_extension || is_extension;
+        enum_.values.extend(values);
+        enum_.directives.extend(directives);
+        Ok(())
+    }
+
+    fn populate_input_object(
+        &mut self,
+        node: &InputObjectTypeDefinition,
+        // `InputObject` has no `is_extension` field to set -- unlike the other
+        // type kinds, it isn't tracked by the schema data model.
+        _is_extension: bool,
+    ) -> Result<(), SchemaError> {
+        let input_object_id = self
+            .schema
+            .get_type(node.name.value)
+            .and_then(Type::get_input_object_id)
+            .ok_or_else(|| SchemaError::UndefinedType(node.name.value))?;
+        let directives = self.build_directive_values(&node.directives)?;
+        let fields = node
+            .fields
+            .iter()
+            .map(|field| self.build_argument(field))
+            .collect::<Result<Vec<_>, SchemaError>>()?;
+        let input_object = self.schema.input_object_mut(input_object_id);
+        input_object.fields.extend(fields);
+        input_object.directives.extend(directives);
+        Ok(())
+    }
+
+    fn populate_directive(&mut self, node: &DirectiveDefinition) -> Result<(), SchemaError> {
+        let arguments = node
+            .arguments
+            .iter()
+            .flat_map(|arguments| arguments.items.iter())
+            .map(|argument| self.build_argument(argument))
+            .collect::<Result<Vec<_>, SchemaError>>()?;
+        self.schema.add_directive(Directive {
+            name: node.name.value,
+            is_extension: false,
+            arguments: ArgumentDefinitions::new(arguments),
+            locations: node.locations.clone(),
+            repeatable: node.repeatable,
+        })
+    }
+
+    fn build_field(
+        &mut self,
+        node: &FieldDefinition,
+        is_extension: bool,
+        parent_type_name: StringKey,
+    ) -> Result<crate::definitions::FieldID, SchemaError> {
+        let arguments = node
+            .arguments
+            .iter()
+            .flat_map(|arguments| arguments.items.iter())
+            .map(|argument| self.build_argument(argument))
+            .collect::<Result<Vec<_>, SchemaError>>()?;
+        let directives = self.build_directive_values(&node.directives)?;
+        let type_ = self.build_type_annotation(&node.type_)?;
+        self.schema.add_field(Field {
+            name: node.name.value,
+            is_extension,
+            arguments: ArgumentDefinitions::new(arguments),
+            type_,
+            directives,
+            parent_type: self.schema.get_type(parent_type_name),
+        })
+    }
+
+    fn build_argument(&mut self, node: &InputValueDefinition) -> Result<Argument, SchemaError> {
+        Ok(Argument {
+            name: node.name.value,
+            type_: self.build_type_annotation(&node.type_)?,
+            default_value: node.default_value.as_ref().map(|value| value.value.clone()),
+        })
+    }
+
+    fn build_type_annotation(
+        &mut self,
+        annotation: &TypeAnnotation,
+    ) -> Result<TypeReference, SchemaError> {
+        Ok(match annotation {
+            TypeAnnotation::Named(named) => TypeReference::Named(
+                self.schema
+                    .get_type(named.name.value)
+                    .ok_or(SchemaError::UndefinedType(named.name.value))?,
+            ),
+            TypeAnnotation::NonNull(non_null) => {
+                TypeReference::NonNull(Box::new(self.build_type_annotation(&non_null.type_)?))
+            }
+            TypeAnnotation::List(list) => {
+                TypeReference::List(Box::new(self.build_type_annotation(&list.type_)?))
+            }
+        })
+    }
+
+    fn build_directive_values(
+        &mut self,
+        directives: &[ConstantDirective],
+    ) -> Result<Vec<DirectiveValue>, SchemaError> {
+        directives
+            .iter()
+            .map(|directive| {
+                let arguments = directive
+                    .arguments
+                    .iter()
+                    .flat_map(|arguments| arguments.items.iter())
+                    .map(|argument| crate::definitions::ArgumentValue {
+                        name: argument.name.value,
+                        value: clone_const_value(&argument.value),
+                    })
+                    .collect();
+                Ok(DirectiveValue {
+                    name: directive.name.value,
+                    arguments,
+                })
+            })
+            .collect()
+    }
+}
+
+fn clone_const_value(value: &ConstantValue) -> ConstantValue {
+    value.clone()
+}